@@ -44,6 +44,7 @@ const LEXER_TESTS = [
   { test: "lexer/comments_single_line.abra", assertions: "lexer/comments_single_line.out.json" },
   { test: "lexer/comments_multiline.abra", assertions: "lexer/comments_multiline.out.json" },
   { test: "lexer/comments_error_multiline_unclosed.abra", assertions: "lexer/comments_error_multiline_unclosed.out" },
+  { test: "lexer/shebang.abra", assertions: "lexer/shebang.out.json" },
 ]
 
 const PARSER_TESTS = [
@@ -830,8 +831,30 @@ const COMPILER_TESTS = [
   { test: "compiler/try_result.abra" },
   { test: "compiler/try_option.abra" },
   { test: "compiler/process.abra", args: ['-f', 'bar', '--baz', 'qux'], env: { FOO: 'bar' } },
+  { test: "compiler/process_stdin.abra", stdin: 'first line\n\nthird\nfourth\nfifth' },
   { test: "compiler/process_callstack.abra" },
+  { test: "compiler/process_panic.abra", exitCode: 1 },
+  { test: "compiler/process_assert.abra", exitCode: 1 },
+  { test: "compiler/process_exit.abra", exitCode: 3 },
+  { test: "compiler/checked_arithmetic.abra", abraArgs: ['--checked-arithmetic'], exitCode: 1 },
+  { test: "compiler/constant_folding_il.abra", abraArgs: ['disasm', '--fn', 'constantFolding'] },
+  { test: "compiler/dead_code_il.abra", abraArgs: ['disasm', '--fn', 'deadCode'] },
+  { test: "compiler/fs.abra" },
+  { test: "compiler/path.abra" },
+  { test: "compiler/net.abra" },
   { test: "compiler/json.abra" },
+  { test: "compiler/math.abra" },
+  { test: "compiler/random.abra" },
+  { test: "compiler/random_panic.abra", exitCode: 1 },
+  { test: "compiler/typechecker_virtual_fs.abra" },
+  { test: "compiler/regex.abra" },
+  { test: "compiler/crypto.abra" },
+  { test: "compiler/encoding.abra" },
+  { test: "compiler/uuid.abra" },
+  { test: "compiler/csv.abra" },
+  { test: "compiler/bytes.abra" },
+  { test: "compiler/time.abra" },
+  { test: "compiler/log.abra" },
 ]
 
 async function main() {
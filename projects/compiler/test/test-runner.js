@@ -21,13 +21,13 @@ class TestRunner {
     }
 
     const results = []
-    for (const { test, assertions, args, env, printModulesOnErr = false } of tests) {
+    for (const { test, assertions, args, abraArgs, env, stdin, exitCode, printModulesOnErr = false } of tests) {
       if (!!assertions) {
         const args = printModulesOnErr ? ['--print-mods-on-err'] : []
         const result = await this._runTest(runnerBin, test, assertions, args)
         results.push(result)
       } else {
-        const result = await this._runCompilerTest(runnerBin, test, args, env)
+        const result = await this._runCompilerTest(runnerBin, test, args, abraArgs, env, stdin, exitCode)
         results.push(result)
       }
     }
@@ -41,7 +41,7 @@ class TestRunner {
 
     try {
       const [actual, expectedOutput] = await Promise.all([
-        runCommand(bin, [testFilePath, ...args]),
+        runCommand(bin, [testFilePath, ...args]).then(({ stdout }) => stdout),
         fs.readFile(outputFilePath, { encoding: 'utf8' }),
       ])
 
@@ -59,36 +59,23 @@ class TestRunner {
     }
   }
 
-  async _runCompilerTest(bin, testFile, args = [], env = {}) {
+  async _runCompilerTest(bin, testFile, args = [], abraArgs = [], env = {}, stdin = '', exitCode = 0) {
     const testFilePath = `${__dirname}/${testFile}`
 
     try {
-      const [actual, expectedOutput] = await Promise.all([
-        runCommand('abra', [testFilePath, ...args], { COMPILER_BIN: bin, ...env }),
+      const [{ stdout, stderr }, expectedOutput] = await Promise.all([
+        runCommand('abra', [...abraArgs, testFilePath, ...args], { COMPILER_BIN: bin, ...env }, stdin, exitCode),
         fs.readFile(testFilePath, { encoding: 'utf8' }),
       ])
 
-      const re = /^\s*\/\/\/ Expect: (.*)$/
-      const expectations = expectedOutput.split('\n')
-        .map((line, idx) => {
-          const match = re.exec(line)
-          if (!match) return null
-
-          const expectation = match[1]
-            .replaceAll('%TEST_DIR%', __dirname)
-            .replaceAll('%STD_DIR%', process.env.ABRA_HOME)
-          return [idx + 1, expectation]
-        })
-        .filter(line => !!line)
-
-      const actualLines = actual.trimEnd().split('\n')
-      let i = 0
-      for (; i < actualLines.length; i++) {
-        const actual = actualLines[i]
-        const expected = expectations[i]
-        if (!expected || actual !== expected[1]) {
-          return { status: 'fail', testFile, expected, actual }
-        }
+      // `/// Expect:` lines are matched against stdout, and `/// ExpectErr:` lines against stderr. Stderr is only checked
+      // if the test has any `/// ExpectErr:` lines.
+      const expectations = parseExpectations(expectedOutput, /^\s*\/\/\/ Expect: (.*)$/)
+      const errExpectations = parseExpectations(expectedOutput, /^\s*\/\/\/ ExpectErr: (.*)$/)
+
+      const mismatch = findMismatch(stdout, expectations) ?? (errExpectations.length ? findMismatch(stderr, errExpectations) : null)
+      if (mismatch) {
+        return { status: 'fail', testFile, ...mismatch }
       }
 
       return { status: 'pass', testFile }
@@ -141,9 +128,37 @@ class TestRunner {
   }
 }
 
-function runCommand(command, args, envVars = {}) {
+function parseExpectations(testFileContents, re) {
+  return testFileContents.split('\n')
+    .map((line, idx) => {
+      const match = re.exec(line)
+      if (!match) return null
+
+      const expectation = match[1]
+        .replaceAll('%TEST_DIR%', __dirname)
+        .replaceAll('%STD_DIR%', process.env.ABRA_HOME)
+      return [idx + 1, expectation]
+    })
+    .filter(line => !!line)
+}
+
+function findMismatch(output, expectations) {
+  const actualLines = output.trimEnd().split('\n')
+  for (let i = 0; i < actualLines.length; i++) {
+    const actual = actualLines[i]
+    const expected = expectations[i]
+    if (!expected || actual !== expected[1]) {
+      return { expected, actual }
+    }
+  }
+
+  return null
+}
+
+function runCommand(command, args, envVars = {}, stdin = '', expectedExitCode = 0) {
   const env = { ...process.env, ...envVars }
   const cmd = childProcess.spawn(command, args, { env })
+  cmd.stdin.end(stdin)
   return new Promise((res, rej) => {
     let stdoutBuf = ''
     cmd.stdout.on('data', data => { stdoutBuf += data })
@@ -151,7 +166,7 @@ function runCommand(command, args, envVars = {}) {
     let stderrBuf = ''
     cmd.stderr.on('data', data => { stderrBuf += data })
 
-    cmd.on('exit', code => code === 0 ? res(stdoutBuf) : rej(stdoutBuf + '\n' + stderrBuf))
+    cmd.on('exit', code => code === expectedExitCode ? res({ stdout: stdoutBuf, stderr: stderrBuf }) : rej(stdoutBuf + '\n' + stderrBuf))
 
     cmd.on('error', err => rej(err))
   })